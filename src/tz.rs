@@ -0,0 +1,744 @@
+//! Time zone support backed by IANA TZif (zoneinfo) data.
+//!
+//! `UtcOffset` only stores a fixed number of seconds, which is enough to
+//! describe an instant but not enough to answer questions like "what was the
+//! offset in `America/New_York` on `2021-03-14T07:00Z`?". A [`TimeZone`] parses
+//! the binary TZif files shipped with the operating system (the zoneinfo
+//! database) and resolves the correct [`UtcOffset`] for any instant, including
+//! daylight saving time transitions.
+
+#[cfg(feature = "alloc")]
+use crate::alloc_prelude::*;
+use crate::{PrimitiveDateTime, UtcOffset};
+
+/// An error that occurred while parsing TZif data.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TzError {
+    /// The data did not begin with the `TZif` magic sequence.
+    InvalidMagic,
+    /// The version byte was not one of `\0`, `2`, or `3`.
+    UnsupportedVersion(u8),
+    /// The data was truncated or otherwise malformed.
+    InvalidData,
+    /// The system time zone could not be determined.
+    #[cfg(feature = "std")]
+    UnknownSystemZone,
+    /// The TZif file could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for TzError {
+    #[inline(always)]
+    fn from(error: std::io::Error) -> Self {
+        TzError::Io(error)
+    }
+}
+
+/// A single local time type, describing the offset that applies between two
+/// transitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalTimeType {
+    /// The offset from UTC, in seconds. Positive is east, negative is west.
+    utoff: i32,
+    /// Whether this type is daylight saving time.
+    isdst: bool,
+    /// The abbreviation for this type (e.g. `EST`), resolved from the string
+    /// table.
+    abbr: String,
+}
+
+/// A transition to a new local time type at a given UTC instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Transition {
+    /// The UTC instant, as a Unix timestamp, at which the transition occurs.
+    timestamp: i64,
+    /// The index into [`TimeZone::local_time_types`] that applies from this
+    /// transition onward.
+    local_time_type: usize,
+}
+
+/// A leap second correction recorded in the TZif data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LeapSecond {
+    /// The UTC instant, as a Unix timestamp, at which the correction applies.
+    timestamp: i64,
+    /// The total correction in effect from this instant onward.
+    correction: i32,
+}
+
+/// A parsed IANA time zone.
+///
+/// Construct one from TZif data with [`TimeZone::parse`], or from the system
+/// zone with [`TimeZone::local`]. Resolve offsets with
+/// [`TimeZone::offset_at`].
+#[derive(Debug, Clone)]
+pub struct TimeZone {
+    /// Transition instants, sorted ascending by UTC timestamp.
+    transitions: Vec<Transition>,
+    /// The local time types referenced by the transitions.
+    local_time_types: Vec<LocalTimeType>,
+    /// Leap second corrections, sorted ascending by UTC timestamp.
+    #[allow(dead_code)]
+    leap_seconds: Vec<LeapSecond>,
+    /// The trailing POSIX TZ string, used to extend transitions past the last
+    /// recorded one.
+    posix: Option<PosixTz>,
+}
+
+impl TimeZone {
+    /// Parse a `TimeZone` from the contents of a TZif (zoneinfo) file.
+    ///
+    /// Both the legacy 32-bit (`TZif\0`) body and the 64-bit v2/v3 body are
+    /// understood; when a 64-bit body is present it is preferred, as it carries
+    /// the trailing POSIX TZ string used to project transitions into the
+    /// future.
+    pub fn parse(bytes: &[u8]) -> Result<Self, TzError> {
+        let header = Header::parse(bytes)?;
+
+        // A v1 body always precedes any 64-bit body. For v2/v3 files we skip
+        // the 32-bit body entirely and re-read the header that introduces the
+        // 64-bit body, which additionally carries the POSIX TZ footer.
+        if header.version == 0 {
+            let (tz, _) = Self::parse_body(&header, &bytes[Header::LEN..], 4)?;
+            Ok(tz)
+        } else {
+            let v1_len = header.data_len(4);
+            let rest = &bytes[Header::LEN + v1_len..];
+            let header64 = Header::parse(rest)?;
+            let (mut tz, consumed) = Self::parse_body(&header64, &rest[Header::LEN..], 8)?;
+            tz.posix = PosixTz::parse_footer(&rest[Header::LEN + consumed..]);
+            Ok(tz)
+        }
+    }
+
+    /// Parse a single TZif body (either the 32-bit or 64-bit variant), returning
+    /// the zone and the number of bytes consumed by the body.
+    fn parse_body(header: &Header, body: &[u8], time_size: usize) -> Result<(Self, usize), TzError> {
+        let mut pos = 0;
+        let read = |pos: &mut usize, len: usize| -> Result<&[u8], TzError> {
+            let slice = body.get(*pos..*pos + len).ok_or(TzError::InvalidData)?;
+            *pos += len;
+            Ok(slice)
+        };
+
+        // Transition time instants.
+        let mut transition_times = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let raw = read(&mut pos, time_size)?;
+            transition_times.push(read_int(raw));
+        }
+
+        // Parallel array of indices into the local time type list.
+        let transition_types = read(&mut pos, header.timecnt)?.to_vec();
+
+        // Local time type records: utoff (i32), isdst (u8), abbrind (u8).
+        let mut types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let raw = read(&mut pos, 6)?;
+            let utoff = i32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]);
+            let isdst = raw[4] != 0;
+            let abbrind = raw[5] as usize;
+            types.push((utoff, isdst, abbrind));
+        }
+
+        // Abbreviation string table.
+        let abbrs = read(&mut pos, header.charcnt)?;
+
+        // Leap second entries: each is a timestamp followed by an i32
+        // correction.
+        let mut leap_seconds = Vec::with_capacity(header.leapcnt);
+        for _ in 0..header.leapcnt {
+            let ts = read_int(read(&mut pos, time_size)?);
+            let correction_raw = read(&mut pos, 4)?;
+            let correction = i32::from_be_bytes([
+                correction_raw[0],
+                correction_raw[1],
+                correction_raw[2],
+                correction_raw[3],
+            ]);
+            leap_seconds.push(LeapSecond {
+                timestamp: ts,
+                correction,
+            });
+        }
+
+        // The standard/wall and UT/local indicator arrays follow; we do not
+        // need them to resolve offsets, but must account for their length.
+        pos += header.isstdcnt + header.isutcnt;
+
+        let local_time_types = types
+            .into_iter()
+            .map(|(utoff, isdst, abbrind)| {
+                Ok(LocalTimeType {
+                    utoff,
+                    isdst,
+                    abbr: read_abbr(abbrs, abbrind)?,
+                })
+            })
+            .collect::<Result<Vec<_>, TzError>>()?;
+
+        if local_time_types.is_empty() {
+            return Err(TzError::InvalidData);
+        }
+
+        let transitions = transition_times
+            .into_iter()
+            .zip(transition_types.iter())
+            .map(|(timestamp, &idx)| {
+                let idx = idx as usize;
+                if idx >= local_time_types.len() {
+                    return Err(TzError::InvalidData);
+                }
+                Ok(Transition {
+                    timestamp,
+                    local_time_type: idx,
+                })
+            })
+            .collect::<Result<Vec<_>, TzError>>()?;
+
+        Ok((
+            Self {
+                transitions,
+                local_time_types,
+                leap_seconds,
+                posix: None,
+            },
+            pos,
+        ))
+    }
+
+    /// Resolve the offset that applies at the given Unix timestamp.
+    fn offset_at_unix(&self, timestamp: i64) -> UtcOffset {
+        // Binary-search for the greatest transition less than or equal to the
+        // instant. `idx` is the number of transitions at or before `timestamp`.
+        let idx = match self
+            .transitions
+            .binary_search_by(|t| t.timestamp.cmp(&timestamp))
+        {
+            // Landed exactly on a transition; it is in effect.
+            Ok(i) => i + 1,
+            // Would be inserted at `i`, so `i` transitions precede the instant.
+            Err(i) => i,
+        };
+
+        if idx == 0 {
+            // A pure-rule zone records no transitions at all; its whole timeline
+            // is described by the POSIX footer, so consult it directly rather
+            // than falling through to the recorded types.
+            if self.transitions.is_empty() {
+                if let Some(posix) = &self.posix {
+                    return posix.offset_at_unix(timestamp);
+                }
+            }
+
+            // Before the first transition: use the first non-DST type, falling
+            // back to the first type if every type is DST.
+            let ltt = self
+                .local_time_types
+                .iter()
+                .find(|t| !t.isdst)
+                .unwrap_or(&self.local_time_types[0]);
+            return UtcOffset::seconds(ltt.utoff);
+        }
+
+        if idx == self.transitions.len() {
+            // Beyond the last recorded transition: defer to the POSIX rule when
+            // one is present.
+            if let Some(posix) = &self.posix {
+                return posix.offset_at_unix(timestamp);
+            }
+        }
+
+        let ltt = &self.local_time_types[self.transitions[idx - 1].local_time_type];
+        UtcOffset::seconds(ltt.utoff)
+    }
+
+    /// Resolve the [`UtcOffset`] in effect at the given datetime.
+    ///
+    /// The datetime is interpreted as UTC; this matches how
+    /// [`UtcOffset::local_offset_at`](crate::UtcOffset::local_offset_at) treats
+    /// its argument.
+    pub fn offset_at(&self, datetime: PrimitiveDateTime) -> UtcOffset {
+        self.offset_at_unix(unix_timestamp(datetime))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeZone {
+    /// Read and parse a `TimeZone` from a TZif file on disk.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, TzError> {
+        Self::parse(&std::fs::read(path)?)
+    }
+
+    /// Load the system's local time zone.
+    ///
+    /// The `TZ` environment variable is consulted first (honoring a leading
+    /// `:`, absolute paths, and names relative to the zoneinfo directory),
+    /// falling back to `/etc/localtime`.
+    pub fn local() -> Result<Self, TzError> {
+        if let Some(tz) = std::env::var_os("TZ") {
+            let tz = tz.to_string_lossy();
+            let tz = tz.strip_prefix(':').unwrap_or(&tz);
+            if !tz.is_empty() {
+                let path = if tz.starts_with('/') {
+                    std::path::PathBuf::from(tz)
+                } else {
+                    std::path::Path::new(ZONEINFO_DIR).join(tz)
+                };
+                return Self::from_file(path);
+            }
+        }
+
+        Self::from_file("/etc/localtime")
+    }
+}
+
+/// The default location of the system zoneinfo database.
+#[cfg(feature = "std")]
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// The fixed-size portion of a TZif header.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// The length of the header, in bytes.
+    const LEN: usize = 44;
+
+    fn parse(bytes: &[u8]) -> Result<Self, TzError> {
+        let bytes = bytes.get(..Self::LEN).ok_or(TzError::InvalidData)?;
+        if &bytes[..4] != b"TZif" {
+            return Err(TzError::InvalidMagic);
+        }
+        let version = match bytes[4] {
+            0 => 0,
+            b'2' => 2,
+            b'3' => 3,
+            other => return Err(TzError::UnsupportedVersion(other)),
+        };
+
+        let count = |offset: usize| {
+            u32::from_be_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]) as usize
+        };
+
+        Ok(Self {
+            version,
+            isutcnt: count(20),
+            isstdcnt: count(24),
+            leapcnt: count(28),
+            timecnt: count(32),
+            typecnt: count(36),
+            charcnt: count(40),
+        })
+    }
+
+    /// The length of the body that follows this header, given the size of a
+    /// transition time (4 bytes for v1, 8 for v2/v3).
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Read a big-endian signed integer of either 4 or 8 bytes.
+fn read_int(bytes: &[u8]) -> i64 {
+    if bytes.len() == 8 {
+        i64::from_be_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ])
+    } else {
+        i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64
+    }
+}
+
+/// Read a NUL-terminated abbreviation from the string table.
+fn read_abbr(table: &[u8], start: usize) -> Result<String, TzError> {
+    let table = table.get(start..).ok_or(TzError::InvalidData)?;
+    let end = table.iter().position(|&b| b == 0).unwrap_or(table.len());
+    Ok(String::from_utf8_lossy(&table[..end]).into_owned())
+}
+
+/// A parsed POSIX TZ string describing standard and (optionally) daylight
+/// saving time, used to project transitions beyond the last recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixTz {
+    /// The standard-time offset from UTC, in seconds (east-positive).
+    std_offset: i32,
+    /// The daylight-time offset and the rules governing it, if any.
+    dst: Option<PosixDst>,
+}
+
+/// The daylight saving portion of a POSIX TZ string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixDst {
+    /// The daylight-time offset from UTC, in seconds (east-positive).
+    offset: i32,
+    /// The rule marking the start of daylight saving time.
+    start: PosixRule,
+    /// The rule marking the end of daylight saving time.
+    end: PosixRule,
+}
+
+/// A `Mm.w.d` day-of-year rule from a POSIX TZ string, with its local time of
+/// day in seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixRule {
+    month: u8,
+    week: u8,
+    day: u8,
+    time: i32,
+}
+
+impl PosixTz {
+    /// Parse the POSIX TZ string that trails a v2/v3 TZif body. The footer is
+    /// delimited by newlines.
+    fn parse_footer(bytes: &[u8]) -> Option<Self> {
+        let text = core::str::from_utf8(bytes).ok()?;
+        let text = text.trim_matches('\n');
+        if text.is_empty() {
+            None
+        } else {
+            Self::parse(text)
+        }
+    }
+
+    /// Parse a POSIX TZ string such as `EST5EDT,M3.2.0,M11.1.0`.
+    fn parse(s: &str) -> Option<Self> {
+        let (_std_abbr, rest) = split_abbr(s)?;
+        let (std_offset, rest) = parse_offset(rest)?;
+        // POSIX offsets are given as the value to add to local time to reach
+        // UTC, i.e. west-positive, which is the opposite of `UtcOffset`.
+        let std_offset = -std_offset;
+
+        if rest.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let (_dst_abbr, rest) = split_abbr(rest)?;
+        let (dst_offset, rest) = if rest.starts_with(',') {
+            // No explicit DST offset: default to one hour east of standard.
+            (std_offset + 3_600, rest)
+        } else {
+            let (off, rest) = parse_offset(rest)?;
+            (-off, rest)
+        };
+
+        let rest = rest.strip_prefix(',')?;
+        let (start, rest) = PosixRule::parse(rest)?;
+        let rest = rest.strip_prefix(',')?;
+        let (end, rest) = PosixRule::parse(rest)?;
+        if !rest.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            std_offset,
+            dst: Some(PosixDst {
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Resolve the offset that applies at the given Unix timestamp using the
+    /// POSIX rules.
+    fn offset_at_unix(&self, timestamp: i64) -> UtcOffset {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return UtcOffset::seconds(self.std_offset),
+        };
+
+        let (year, _, _) = civil_from_days(timestamp.div_euclid(86_400));
+        // The start instant is expressed in local standard time and the end
+        // instant in local daylight time, so convert each to UTC using the
+        // offset in effect just before it.
+        let start = dst.start.transition_unix(year) - self.std_offset as i64;
+        let end = dst.end.transition_unix(year) - dst.offset as i64;
+
+        let is_dst = if start <= end {
+            timestamp >= start && timestamp < end
+        } else {
+            // Southern hemisphere: DST wraps across the new year.
+            timestamp >= start || timestamp < end
+        };
+
+        if is_dst {
+            UtcOffset::seconds(dst.offset)
+        } else {
+            UtcOffset::seconds(self.std_offset)
+        }
+    }
+}
+
+impl PosixRule {
+    /// Parse a single `Mm.w.d[/time]` rule, returning it and the unparsed
+    /// remainder. Only the month/week/day form is supported.
+    fn parse(s: &str) -> Option<(Self, &str)> {
+        let s = s.strip_prefix('M')?;
+        let (month, s) = parse_u8(s)?;
+        let s = s.strip_prefix('.')?;
+        let (week, s) = parse_u8(s)?;
+        let s = s.strip_prefix('.')?;
+        let (day, s) = parse_u8(s)?;
+
+        let (time, s) = if let Some(s) = s.strip_prefix('/') {
+            let (offset, s) = parse_offset(s)?;
+            (offset, s)
+        } else {
+            // POSIX defaults the transition time to 02:00 local.
+            (2 * 3_600, s)
+        };
+
+        Some((
+            Self {
+                month,
+                week,
+                day,
+                time,
+            },
+            s,
+        ))
+    }
+
+    /// The Unix timestamp, in the local wall-clock time the rule is written in
+    /// (as if that local time were UTC), of this rule in the given year.
+    fn transition_unix(&self, year: i64) -> i64 {
+        let day = self.day_of_month(year);
+        days_from_civil(year, self.month as i64, day) * 86_400 + self.time as i64
+    }
+
+    /// The day of the month this rule falls on in the given year.
+    ///
+    /// `week` 1..=4 selects the nth occurrence of the weekday; `week` 5 selects
+    /// the last occurrence.
+    fn day_of_month(&self, year: i64) -> i64 {
+        // `day` is 0 (Sunday) through 6 (Saturday).
+        let first = days_from_civil(year, self.month as i64, 1);
+        let first_weekday = weekday(first);
+        let mut dom = 1 + (self.day as i64 - first_weekday).rem_euclid(7);
+        if self.week >= 2 {
+            dom += 7 * (self.week as i64 - 1);
+        }
+        let days_in_month = days_in_month(year, self.month);
+        while dom > days_in_month {
+            dom -= 7;
+        }
+        dom
+    }
+}
+
+/// Parse a leading run of decimal digits into a `u8`.
+fn parse_u8(s: &str) -> Option<(u8, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if end == 0 {
+        return None;
+    }
+    Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+/// Parse a signed POSIX `[+-]hh[:mm[:ss]]` offset into seconds.
+fn parse_offset(s: &str) -> Option<(i32, &str)> {
+    let (sign, s) = match s.as_bytes().first() {
+        Some(b'-') => (-1, &s[1..]),
+        Some(b'+') => (1, &s[1..]),
+        _ => (1, s),
+    };
+
+    let (hours, s) = parse_u8(s)?;
+    let mut total = hours as i32 * 3_600;
+    let mut s = s;
+    if let Some(rest) = s.strip_prefix(':') {
+        let (minutes, rest) = parse_u8(rest)?;
+        total += minutes as i32 * 60;
+        s = rest;
+        if let Some(rest) = s.strip_prefix(':') {
+            let (seconds, rest) = parse_u8(rest)?;
+            total += seconds as i32;
+            s = rest;
+        }
+    }
+
+    Some((sign * total, s))
+}
+
+/// Split a (possibly `<...>`-quoted) POSIX abbreviation from the front of a
+/// string, returning the abbreviation and the remainder.
+fn split_abbr(s: &str) -> Option<(&str, &str)> {
+    if let Some(rest) = s.strip_prefix('<') {
+        let end = rest.find('>')?;
+        Some((&rest[..end], &rest[end + 1..]))
+    } else {
+        let end = s
+            .find(|c: char| c.is_ascii_digit() || c == '+' || c == '-' || c == ',')
+            .unwrap_or(s.len());
+        if end == 0 {
+            None
+        } else {
+            Some((&s[..end], &s[end..]))
+        }
+    }
+}
+
+/// Convert a `PrimitiveDateTime`, interpreted as UTC, to a Unix timestamp.
+fn unix_timestamp(datetime: PrimitiveDateTime) -> i64 {
+    let (month, day) = datetime.month_day();
+    let days = days_from_civil(datetime.date.year() as i64, month as i64, day as i64);
+    days * 86_400
+        + datetime.time.hour() as i64 * 3_600
+        + datetime.time.minute() as i64 * 60
+        + datetime.time.second() as i64
+}
+
+/// The number of days from the Unix epoch to the given civil date, after Howard
+/// Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// The inverse of [`days_from_civil`], yielding `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let year = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (
+        if month <= 2 { year + 1 } else { year },
+        month as u8,
+        day as u8,
+    )
+}
+
+/// The day of the week for a Unix day number, 0 (Sunday) through 6 (Saturday).
+fn weekday(days: i64) -> i64 {
+    // The Unix epoch (day 0) was a Thursday, which is weekday 4.
+    (days + 4).rem_euclid(7)
+}
+
+/// The number of days in the given month of the given year.
+fn days_in_month(year: i64, month: u8) -> i64 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Whether the given year is a Gregorian leap year.
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The UTC Unix timestamp for the given civil date and hour.
+    fn ts(year: i64, month: i64, day: i64, hour: i64) -> i64 {
+        days_from_civil(year, month, day) * 86_400 + hour * 3_600
+    }
+
+    /// Build a minimal v1 TZif image: one transition at the epoch from `EST`
+    /// (non-DST, -05:00) to `EDT` (DST, -04:00).
+    fn tzif_est_edt() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"TZif");
+        data.push(0); // v1
+        data.extend_from_slice(&[0u8; 15]); // reserved
+        for count in [0u32, 0, 0, 1, 2, 8] {
+            // isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt
+            data.extend_from_slice(&count.to_be_bytes());
+        }
+        data.extend_from_slice(&0i32.to_be_bytes()); // transition at the epoch
+        data.push(1); // ...to local time type 1
+        data.extend_from_slice(&(-18_000i32).to_be_bytes()); // type 0: EST
+        data.push(0);
+        data.push(0);
+        data.extend_from_slice(&(-14_400i32).to_be_bytes()); // type 1: EDT
+        data.push(1);
+        data.push(4);
+        data.extend_from_slice(b"EST\0EDT\0");
+        data
+    }
+
+    #[test]
+    fn parse_and_resolve_tzif() {
+        let tz = TimeZone::parse(&tzif_est_edt()).unwrap();
+        // Before the first transition the first non-DST type applies.
+        assert_eq!(tz.offset_at_unix(-1), UtcOffset::seconds(-18_000));
+        // From the transition onward the DST type applies.
+        assert_eq!(tz.offset_at_unix(0), UtcOffset::seconds(-14_400));
+    }
+
+    #[test]
+    fn posix_northern_hemisphere() {
+        let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        // Standard time in winter, daylight time in summer.
+        assert_eq!(tz.offset_at_unix(ts(2021, 1, 15, 12)), UtcOffset::seconds(-18_000));
+        assert_eq!(tz.offset_at_unix(ts(2021, 7, 15, 12)), UtcOffset::seconds(-14_400));
+        // The spring-forward transition is at 2021-03-14T07:00Z (02:00 local).
+        let spring = ts(2021, 3, 14, 7);
+        assert_eq!(tz.offset_at_unix(spring - 1), UtcOffset::seconds(-18_000));
+        assert_eq!(tz.offset_at_unix(spring), UtcOffset::seconds(-14_400));
+    }
+
+    #[test]
+    fn posix_southern_hemisphere() {
+        // Australian eastern time: DST straddles the new year.
+        let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert_eq!(tz.offset_at_unix(ts(2021, 1, 15, 12)), UtcOffset::seconds(39_600));
+        assert_eq!(tz.offset_at_unix(ts(2021, 7, 15, 12)), UtcOffset::seconds(36_000));
+    }
+
+    #[test]
+    fn pure_rule_zone_uses_posix() {
+        // A zone with no recorded transitions must still honour its POSIX footer.
+        let tz = TimeZone {
+            transitions: Vec::new(),
+            local_time_types: vec![LocalTimeType {
+                utoff: 0,
+                isdst: false,
+                abbr: String::from("LMT"),
+            }],
+            leap_seconds: Vec::new(),
+            posix: PosixTz::parse("EST5EDT,M3.2.0,M11.1.0"),
+        };
+        assert_eq!(tz.offset_at_unix(ts(2021, 1, 15, 12)), UtcOffset::seconds(-18_000));
+        assert_eq!(tz.offset_at_unix(ts(2021, 7, 15, 12)), UtcOffset::seconds(-14_400));
+    }
+}
@@ -12,15 +12,67 @@ use crate::{
 /// you need support outside this range, please file an issue with your use
 /// case.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(from = "crate::serde::UtcOffset", into = "crate::serde::UtcOffset")
-)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone)]
 pub struct UtcOffset {
     /// The number of seconds offset from UTC. Positive is east, negative is
     /// west.
     pub(crate) seconds: i32,
+    /// Whether the offset is the RFC 2822 / RFC 3339 "unknown local offset"
+    /// (`-0000`), which is distinct from a true UTC offset of `+0000`. The
+    /// numeric value is still zero; only the sign emitted on formatting
+    /// differs.
+    ///
+    /// Serialization is derived from this struct's fields. The field is
+    /// `default`ed and skipped when unset, so ordinary offsets serialize with
+    /// only `seconds`, and input without it deserializes with the flag
+    /// cleared. A bare number of seconds is not accepted on deserialization.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "is_not_unknown")
+    )]
+    pub(crate) unknown: bool,
+}
+
+/// The `unknown` flag does not participate in equality, ordering, or hashing:
+/// those stay keyed solely on the numeric offset, so [`UtcOffset::UNKNOWN`]
+/// compares, sorts, and hashes exactly like a `+00:00` offset. The distinction
+/// is observable only through formatting, which re-emits it as `-0000`.
+impl PartialEq for UtcOffset {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+    }
+}
+
+impl Eq for UtcOffset {}
+
+impl core::hash::Hash for UtcOffset {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.seconds.hash(state);
+    }
+}
+
+impl PartialOrd for UtcOffset {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UtcOffset {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.seconds.cmp(&other.seconds)
+    }
+}
+
+/// Whether the offset is *not* the unknown local offset; keeps the flag out of
+/// the serialized form unless it is set.
+#[cfg(feature = "serde")]
+#[inline(always)]
+fn is_not_unknown(unknown: &bool) -> bool {
+    !*unknown
 }
 
 impl UtcOffset {
@@ -32,6 +84,24 @@ impl UtcOffset {
     /// ```
     pub const UTC: Self = Self::seconds(0);
 
+    /// A `UtcOffset` representing an unknown local offset, per RFC 2822 and
+    /// RFC 3339 (`-0000`).
+    ///
+    /// Its numeric value is zero, so [`as_seconds`](Self::as_seconds) and the
+    /// other accessors report `0` and it compares equal to [`UTC`](Self::UTC);
+    /// the distinction is purely in formatting, where it is re-emitted as
+    /// `-0000` rather than `+0000`.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::UNKNOWN.as_seconds(), 0);
+    /// assert_eq!(UtcOffset::UNKNOWN.format("%z"), "-0000");
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        seconds: 0,
+        unknown: true,
+    };
+
     /// Create a `UtcOffset` representing an easterly offset by the number of
     /// hours provided.
     ///
@@ -144,7 +214,10 @@ impl UtcOffset {
     /// ```
     #[inline(always)]
     pub const fn seconds(seconds: i32) -> Self {
-        Self { seconds }
+        Self {
+            seconds,
+            unknown: false,
+        }
     }
 
     /// Get the number of seconds from UTC the value is. Positive is east,
@@ -209,6 +282,14 @@ impl UtcOffset {
     /// ```
     #[inline(always)]
     pub fn format(self, format: &str) -> String {
+        // The `%z`, `%:z`, `%:::z`, and `%#z` specifiers are rendered here
+        // wherever they appear in the format string (so the unknown offset's
+        // `-0000` survives the plain `%z` path); a format that mixes in a date
+        // or time specifier, which a bare offset cannot supply, defers to the
+        // format module as before.
+        if let Some(formatted) = self.format_with_offsets(format) {
+            return formatted;
+        }
         DeferredFormat {
             date: None,
             time: None,
@@ -218,6 +299,21 @@ impl UtcOffset {
         .to_string()
     }
 
+    /// Render `format` directly when it contains only offset specifiers and
+    /// literal text, returning `None` if any other specifier appears.
+    fn format_with_offsets(self, format: &str) -> Option<String> {
+        let mut out = String::new();
+        let mut rest = format;
+        while let Some(at) = rest.find('%') {
+            out.push_str(&rest[..at]);
+            let (spec, len) = OffsetSpec::strip(&rest[at..])?;
+            out.push_str(&self.format_offset(spec.colon, spec.seconds));
+            rest = &rest[at + len..];
+        }
+        out.push_str(rest);
+        Some(out)
+    }
+
     /// Attempt to parse the `UtcOffset` using the provided string.
     ///
     /// ```rust
@@ -227,14 +323,183 @@ impl UtcOffset {
     /// ```
     #[inline(always)]
     pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
+        if let Some(offset) = Self::parse_with_offsets(s, format) {
+            return Ok(offset);
+        }
         Self::try_from_parsed_items(parse(s, format)?)
     }
 
+    /// Parse `s` against `format` directly when the format contains only offset
+    /// specifiers and literal text, matching each specifier wherever it appears.
+    /// Returns `None` (deferring to the format module) on any other specifier or
+    /// a literal mismatch.
+    fn parse_with_offsets(s: &str, format: &str) -> Option<Self> {
+        let mut input = s;
+        let mut rest = format;
+        let mut offset = None;
+        while let Some(at) = rest.find('%') {
+            input = input.strip_prefix(&rest[..at])?;
+            let (spec, len) = OffsetSpec::strip(&rest[at..])?;
+            let (parsed, remainder) = Self::parse_offset_prefix(input, spec.permissive)?;
+            offset = Some(parsed);
+            input = remainder;
+            rest = &rest[at + len..];
+        }
+        input = input.strip_prefix(rest)?;
+        if input.is_empty() {
+            offset
+        } else {
+            None
+        }
+    }
+
     /// Given the items already parsed, attempt to create a `UtcOffset`.
     #[inline(always)]
     pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
         items.offset.ok_or(ParseError::InsufficientInformation)
     }
+
+    /// Parse an ISO 8601 / RFC 3339 numeric offset.
+    ///
+    /// A bare `Z` or `z` is accepted as [`UtcOffset::UTC`]. Otherwise the value
+    /// is `±HH`, `±HHMM`, `±HH:MM`, `±HHMMSS`, or `±HH:MM:SS`; the colon
+    /// separators are optional. When `permissive` is `true` the minutes field
+    /// may be omitted, so `+09`, `+0900`, and `+09:00` all parse alike; when it
+    /// is `false` the minutes field is required, matching the strict `%z` form.
+    ///
+    /// This backs the `%z`, `%:z`, `%:::z`, and permissive `%#z` specifiers
+    /// accepted by [`UtcOffset::parse`].
+    pub(crate) fn parse_offset(s: &str, permissive: bool) -> Option<Self> {
+        let (offset, rest) = Self::parse_offset_prefix(s, permissive)?;
+        if rest.is_empty() {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    /// Parse an offset from the front of `s`, returning it alongside the
+    /// unconsumed remainder. This lets [`UtcOffset::parse`] match an offset
+    /// specifier embedded in a larger format string.
+    fn parse_offset_prefix(s: &str, permissive: bool) -> Option<(Self, &str)> {
+        if let Some(rest) = s.strip_prefix('Z').or_else(|| s.strip_prefix('z')) {
+            return Some((Self::UTC, rest));
+        }
+
+        let bytes = s.as_bytes();
+        let sign = match bytes.first()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+
+        let (hours, mut rest) = two_digits(&bytes[1..])?;
+        let mut minutes = 0;
+        let mut seconds = 0;
+
+        if let Some((m, after_minutes)) = two_digits(rest.strip_prefix(b":").unwrap_or(rest)) {
+            minutes = m;
+            rest = after_minutes;
+
+            if let Some((sec, after_seconds)) =
+                two_digits(rest.strip_prefix(b":").unwrap_or(rest))
+            {
+                seconds = sec;
+                rest = after_seconds;
+            }
+        } else if !permissive {
+            return None;
+        }
+
+        let total = sign * (hours * 3_600 + minutes * 60 + seconds);
+        let offset = Self {
+            seconds: total,
+            // A negative-signed all-zero offset (`-0000`) is the "unknown local
+            // offset", distinct from a true `+0000`.
+            unknown: sign < 0 && total == 0,
+        };
+        // The remaining bytes are everything the offset did not consume; only
+        // ASCII was consumed, so this is always a valid `str` boundary.
+        Some((offset, &s[s.len() - rest.len()..]))
+    }
+
+    /// Format the offset as an ISO 8601 string.
+    ///
+    /// `colon` selects the `±HH:MM` form over `±HHMM`, and `seconds` appends the
+    /// seconds field. The sign always appears, tracking the sign of the stored
+    /// offset even when the hour and minute fields are both zero; the unknown
+    /// local offset is emitted with a `-` sign.
+    ///
+    /// This backs the `%z`, `%:z`, and `%:::z` specifiers emitted by
+    /// [`UtcOffset::format`].
+    pub(crate) fn format_offset(self, colon: bool, seconds: bool) -> String {
+        let sign = if self.seconds < 0 || self.unknown {
+            '-'
+        } else {
+            '+'
+        };
+        let total = self.seconds.abs();
+        let sep = if colon { ":" } else { "" };
+        if seconds {
+            format!(
+                "{}{:02}{}{:02}{}{:02}",
+                sign,
+                total / 3_600,
+                sep,
+                total / 60 % 60,
+                sep,
+                total % 60
+            )
+        } else {
+            format!("{}{:02}{}{:02}", sign, total / 3_600, sep, total / 60 % 60)
+        }
+    }
+}
+
+/// How an offset format specifier should be parsed and formatted.
+struct OffsetSpec {
+    /// Emit (and accept) the `±HH:MM` colon separator.
+    colon: bool,
+    /// Include the seconds field (`±HH:MM:SS`).
+    seconds: bool,
+    /// Tolerate a missing minutes field (`+09`), mirroring the `%#z` idea.
+    permissive: bool,
+}
+
+impl OffsetSpec {
+    /// If `s` begins with an offset specifier, return how to interpret it along
+    /// with its length in bytes; longer specifiers are matched first so `%:::z`
+    /// is not mistaken for `%:z`. Any other specifier yields `None`, deferring
+    /// to the format module.
+    fn strip(s: &str) -> Option<(Self, usize)> {
+        let spec = |colon, seconds, permissive| Self {
+            colon,
+            seconds,
+            permissive,
+        };
+        if s.starts_with("%:::z") {
+            Some((spec(true, true, false), 5))
+        } else if s.starts_with("%#z") {
+            Some((spec(false, false, true), 3))
+        } else if s.starts_with("%:z") {
+            Some((spec(true, false, false), 3))
+        } else if s.starts_with("%z") {
+            Some((spec(false, false, false), 2))
+        } else {
+            None
+        }
+    }
+}
+
+/// Read exactly two ASCII digits from the front of `bytes`, returning their
+/// value and the remainder.
+fn two_digits(bytes: &[u8]) -> Option<(i32, &[u8])> {
+    match bytes {
+        [a, b, rest @ ..] if a.is_ascii_digit() && b.is_ascii_digit() => {
+            Some(((a - b'0') as i32 * 10 + (b - b'0') as i32, rest))
+        }
+        _ => None,
+    }
 }
 
 #[cfg(target_family = "windows")]
@@ -318,7 +583,7 @@ impl UtcOffset {
         let diff_secs = Self::filetime_to_secs(&ft_local) - Self::filetime_to_secs(&ft_system);
 
         i32::try_from(diff_secs)
-            .map(|s| Self { seconds: s })
+            .map(Self::seconds)
             .unwrap_or(Self::UTC)
     }
 
@@ -351,13 +616,33 @@ impl UtcOffset {
                 // call failed somehow, return UTC
                 _ => return Self::UTC,
             };
-            UtcOffset {
-                seconds: bias_mins * -60,
-            }
+            UtcOffset::seconds(bias_mins * -60)
         }
     }
 }
 
+#[cfg(all(not(target_family = "windows"), feature = "std"))]
+impl UtcOffset {
+    /// Resolve the local offset for the given datetime by reading the system's
+    /// TZif data.
+    ///
+    /// Like the Windows implementation, this falls back to [`Self::UTC`] if the
+    /// system zone cannot be determined or parsed.
+    pub fn local_offset_at(datetime: time::PrimitiveDateTime) -> Self {
+        match crate::tz::TimeZone::local() {
+            Ok(tz) => tz.offset_at(datetime),
+            Err(_) => Self::UTC,
+        }
+    }
+
+    /// Get the current local offset, resolved from the system zone.
+    ///
+    /// Falls back to [`Self::UTC`] on any failure.
+    pub fn local() -> Self {
+        Self::local_offset_at(time::PrimitiveDateTime::now())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -458,6 +743,9 @@ mod test {
         // Seconds are not displayed, but the sign can still change.
         assert_eq!(offset!(+0:00:01).format("%z"), "+0000");
         assert_eq!(offset!(-0:00:01).format("%z"), "-0000");
+
+        // The unknown offset re-emits as `-0000` through the plain `%z` path.
+        assert_eq!(UtcOffset::UNKNOWN.format("%z"), "-0000");
     }
 
     #[test]
@@ -465,10 +753,90 @@ mod test {
         assert_eq!(UtcOffset::parse("+0100", "%z"), Ok(offset!(+1)));
         assert_eq!(UtcOffset::parse("-0100", "%z"), Ok(offset!(-1)));
         assert_eq!(UtcOffset::parse("+0000", "%z"), Ok(offset!(+0)));
-        assert_eq!(UtcOffset::parse("-0000", "%z"), Ok(offset!(+0)));
-
         assert_eq!(UtcOffset::parse("+0001", "%z"), Ok(offset!(+0:01)));
         assert_eq!(UtcOffset::parse("-0001", "%z"), Ok(offset!(-0:01)));
+
+        // `-0000` through `%z` sets the unknown flag, observable on re-format.
+        assert_eq!(UtcOffset::parse("-0000", "%z").unwrap().format("%z"), "-0000");
+        assert_eq!(UtcOffset::parse("+0000", "%z").unwrap().format("%z"), "+0000");
+    }
+
+    #[test]
+    fn parse_colon_and_permissive_specifiers() {
+        // The colon, seconds, and permissive forms parse through the public
+        // API; a bare `Z` maps to UTC.
+        assert_eq!(UtcOffset::parse("+09:00", "%:z"), Ok(offset!(+9)));
+        assert_eq!(UtcOffset::parse("+01:02:03", "%:::z"), Ok(offset!(+1:02:03)));
+        assert_eq!(UtcOffset::parse("+09", "%#z"), Ok(offset!(+9)));
+        assert_eq!(UtcOffset::parse("Z", "%:z"), Ok(UtcOffset::UTC));
+    }
+
+    #[test]
+    fn format_colon_specifiers() {
+        assert_eq!(offset!(+9).format("%:z"), "+09:00");
+        assert_eq!(offset!(+1:02:03).format("%:::z"), "+01:02:03");
+    }
+
+    #[test]
+    fn offset_specifiers_in_compound_formats() {
+        // Offset specifiers are honored wherever they appear, not only as the
+        // entire format string.
+        assert_eq!(offset!(+9).format("[%:z]"), "[+09:00]");
+        assert_eq!(UtcOffset::parse("[+09:00]", "[%:z]"), Ok(offset!(+9)));
+        assert_eq!(UtcOffset::parse("T-04:30", "T%:z"), Ok(offset!(-4:30)));
+    }
+
+    #[test]
+    fn parse_offset_colon() {
+        assert_eq!(UtcOffset::parse_offset("+09:00", false), Some(offset!(+9)));
+        assert_eq!(UtcOffset::parse_offset("-09:30", false), Some(offset!(-9:30)));
+        assert_eq!(
+            UtcOffset::parse_offset("+01:02:03", false),
+            Some(offset!(+1:02:03))
+        );
+        assert_eq!(UtcOffset::parse_offset("Z", false), Some(UtcOffset::UTC));
+        assert_eq!(UtcOffset::parse_offset("z", false), Some(UtcOffset::UTC));
+    }
+
+    #[test]
+    fn parse_offset_permissive() {
+        // A missing minutes field is tolerated only in permissive mode.
+        assert_eq!(UtcOffset::parse_offset("+09", false), None);
+        assert_eq!(UtcOffset::parse_offset("+09", true), Some(offset!(+9)));
+        assert_eq!(UtcOffset::parse_offset("+0900", true), Some(offset!(+9)));
+        assert_eq!(UtcOffset::parse_offset("+09:00", true), Some(offset!(+9)));
+    }
+
+    #[test]
+    fn format_offset_forms() {
+        assert_eq!(offset!(+9).format_offset(false, false), "+0900");
+        assert_eq!(offset!(+9).format_offset(true, false), "+09:00");
+        assert_eq!(offset!(+1:02:03).format_offset(true, true), "+01:02:03");
+        // The sign tracks the stored offset even when hours and minutes are zero.
+        assert_eq!(offset!(-0:00:01).format_offset(false, false), "-0000");
+    }
+
+    #[test]
+    fn unknown_offset() {
+        // The numeric value and comparisons match UTC; the distinction is
+        // purely in formatting.
+        assert_eq!(UtcOffset::UNKNOWN.as_seconds(), 0);
+        assert_eq!(UtcOffset::UNKNOWN, UtcOffset::UTC);
+
+        // `-0000` parses to the unknown offset and `+0000` to true UTC, a
+        // difference observable only when re-formatted.
+        assert_eq!(
+            UtcOffset::parse_offset("-0000", false).unwrap().format_offset(false, false),
+            "-0000"
+        );
+        assert_eq!(
+            UtcOffset::parse_offset("+0000", false).unwrap().format_offset(false, false),
+            "+0000"
+        );
+
+        // The constants format with their distinct signs.
+        assert_eq!(UtcOffset::UNKNOWN.format_offset(false, false), "-0000");
+        assert_eq!(UtcOffset::UTC.format_offset(false, false), "+0000");
     }
 
     #[test]